@@ -1,12 +1,25 @@
 use base64::{engine::general_purpose::STANDARD, Engine};
 use gloo::file::callbacks::FileReader;
 use gloo::file::File;
+use image::imageops::FilterType;
+use image::ImageOutputFormat;
+use js_sys::{Array, Uint8Array};
 use std::collections::HashMap;
-use web_sys::{Event, HtmlInputElement, HtmlImageElement, MouseEvent};
+use std::io::Cursor;
+use web_sys::{
+    Blob, BlobPropertyBag, ClipboardEvent, DragEvent, Event, HtmlImageElement, HtmlInputElement,
+    HtmlTextAreaElement, InputEvent, MouseEvent, Url,
+};
 use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen::{JsCast, closure::Closure};
 use yew::prelude::*;
 
+const DEFAULT_QUALITY: u8 = 80;
+const DEFAULT_MAX_DIMENSION: u32 = 1920;
+/// Quality used when re-encoding JPEG solely to drop metadata — high enough
+/// that the EXIF strip itself isn't a visible recompression.
+const EXIF_STRIP_JPEG_QUALITY: u8 = 95;
+
 pub enum Msg {
     FileSelected(File),
     Loaded(String),
@@ -16,6 +29,31 @@ pub enum Msg {
     ResetCopyButton,
     UpdateDimensions(String),
     UpdateImageInfo(String, String),
+    DragOver,
+    DragLeave,
+    Drop(DragEvent),
+    RawLoaded(Vec<u8>),
+    UpdateQuality(u8),
+    UpdateMaxDimension(u32),
+    ToggleStripExif,
+    CopyDataUri,
+    ResetCopyUriButton,
+    CopyCompressedBase64,
+    ResetCompressedCopyButton,
+    CopySha256,
+    ResetSha256Copy,
+    CopyMd5,
+    ResetMd5Copy,
+    ToggleMode,
+    DecodeInput(String),
+    DecodeSubmit,
+}
+
+#[derive(Clone, Copy, PartialEq, Default)]
+enum Mode {
+    #[default]
+    Encode,
+    Decode,
 }
 
 #[derive(Clone, Default)]
@@ -25,6 +63,14 @@ struct ImageInfo {
     dimensions: String,
     mime_type: String,
     aspect_ratio: String,
+    compressed_size: String,
+    exif_camera: String,
+    exif_orientation: String,
+    exif_timestamp: String,
+    exif_gps: String,
+    exif_has_gps: bool,
+    sha256: String,
+    md5: String,
 }
 
 pub struct Model {
@@ -33,6 +79,22 @@ pub struct Model {
     modal_open: bool,
     copy_button_text: String,
     image_info: Option<ImageInfo>,
+    drag_active: bool,
+    _paste_closure: Option<Closure<dyn FnMut(ClipboardEvent)>>,
+    original_bytes: Option<Vec<u8>>,
+    compressed_base64: Option<String>,
+    quality: u8,
+    max_dimension: u32,
+    strip_exif: bool,
+    mime_type: String,
+    copy_uri_button_text: String,
+    sha256_copy_text: String,
+    md5_copy_text: String,
+    compressed_copy_button_text: String,
+    mode: Mode,
+    decode_text: String,
+    decode_error: Option<String>,
+    decoded_object_url: Option<String>,
 }
 
 impl Component for Model {
@@ -46,6 +108,48 @@ impl Component for Model {
             modal_open: false,
             copy_button_text: "复制".to_string(),
             image_info: None,
+            drag_active: false,
+            _paste_closure: None,
+            original_bytes: None,
+            compressed_base64: None,
+            quality: DEFAULT_QUALITY,
+            max_dimension: DEFAULT_MAX_DIMENSION,
+            strip_exif: false,
+            mime_type: String::new(),
+            copy_uri_button_text: "复制Data URI".to_string(),
+            sha256_copy_text: "复制".to_string(),
+            md5_copy_text: "复制".to_string(),
+            compressed_copy_button_text: "复制压缩后Base64".to_string(),
+            mode: Mode::Encode,
+            decode_text: String::new(),
+            decode_error: None,
+            decoded_object_url: None,
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if !first_render {
+            return;
+        }
+        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+            let link = ctx.link().clone();
+            let on_paste = Closure::wrap(Box::new(move |event: ClipboardEvent| {
+                if let Some(clipboard_data) = event.clipboard_data() {
+                    let items = clipboard_data.items();
+                    for i in 0..items.length() {
+                        if let Some(item) = items.get(i) {
+                            if item.type_().starts_with("image/") {
+                                if let Ok(Some(file)) = item.get_as_file() {
+                                    link.send_message(Msg::FileSelected(File::from(file)));
+                                }
+                            }
+                        }
+                    }
+                }
+            }) as Box<dyn FnMut(ClipboardEvent)>);
+            let _ = document
+                .add_event_listener_with_callback("paste", on_paste.as_ref().unchecked_ref());
+            self._paste_closure = Some(on_paste);
         }
     }
 
@@ -56,26 +160,66 @@ impl Component for Model {
                 let size = file.size();
                 let format = get_file_format(&file_name);
                 let mime_type = file.raw_mime_type();
-                
+                self.mime_type = mime_type.clone();
+
                 self.image_info = Some(ImageInfo {
                     format,
                     size: format_size(size),
                     dimensions: String::from("加载中..."),
                     mime_type,
                     aspect_ratio: String::from("加载中..."),
+                    ..ImageInfo::default()
                 });
 
                 let task = {
                     let link = ctx.link().clone();
                     gloo::file::callbacks::read_as_bytes(&file, move |res| {
                         let bytes = res.expect("failed to read file");
-                        let base64 = STANDARD.encode(bytes);
+                        let base64 = STANDARD.encode(&bytes);
                         link.send_message(Msg::Loaded(base64));
+                        link.send_message(Msg::RawLoaded(bytes));
                     })
                 };
                 self.readers.insert(file_name, task);
                 true
             }
+            Msg::RawLoaded(bytes) => {
+                if self.mime_type.is_empty() {
+                    self.mime_type = sniff_mime_type(&bytes);
+                }
+                if let Some(info) = &mut self.image_info {
+                    if info.mime_type.is_empty() {
+                        info.mime_type = self.mime_type.clone();
+                    }
+                    let exif = parse_exif(&bytes);
+                    info.exif_camera = exif.camera;
+                    info.exif_orientation = exif.orientation;
+                    info.exif_timestamp = exif.timestamp;
+                    info.exif_gps = exif.gps;
+                    info.exif_has_gps = exif.has_gps;
+                    info.sha256 = compute_sha256(&bytes);
+                    info.md5 = compute_md5(&bytes);
+                }
+                self.original_bytes = Some(bytes);
+                self.recompress();
+                self.apply_exif_stripping();
+                true
+            }
+            Msg::UpdateQuality(quality) => {
+                self.quality = quality;
+                self.recompress();
+                true
+            }
+            Msg::UpdateMaxDimension(max_dimension) => {
+                self.max_dimension = max_dimension;
+                self.recompress();
+                true
+            }
+            Msg::ToggleStripExif => {
+                self.strip_exif = !self.strip_exif;
+                self.apply_exif_stripping();
+                true
+            }
             Msg::Loaded(data) => {
                 self.base64_data = Some(data.clone());
                 if let Some(_) = &mut self.image_info {
@@ -95,7 +239,7 @@ impl Component for Model {
                     
                     img.set_onload(Some(on_load.as_ref().unchecked_ref()));
                     on_load.forget();
-                    img.set_src(&format!("data:image/png;base64,{}", data));
+                    img.set_src(&format!("data:{};base64,{}", self.mime_type_or_default(), data));
                 }
                 true
             }
@@ -106,11 +250,36 @@ impl Component for Model {
                 true
             }
             Msg::Files(files) => {
+                self.drag_active = false;
                 for file in files.into_iter() {
                     ctx.link().send_message(Msg::FileSelected(file));
                 }
                 true
             }
+            Msg::DragOver => {
+                self.drag_active = true;
+                true
+            }
+            Msg::DragLeave => {
+                self.drag_active = false;
+                true
+            }
+            Msg::Drop(event) => {
+                event.prevent_default();
+                self.drag_active = false;
+                if let Some(data_transfer) = event.data_transfer() {
+                    if let Some(files) = data_transfer.files() {
+                        let mut result = Vec::new();
+                        for i in 0..files.length() {
+                            if let Some(file) = files.get(i) {
+                                result.push(File::from(file));
+                            }
+                        }
+                        ctx.link().send_message(Msg::Files(result));
+                    }
+                }
+                true
+            }
             Msg::ToggleModal => {
                 self.modal_open = !self.modal_open;
                 true
@@ -140,6 +309,100 @@ impl Component for Model {
                 self.copy_button_text = "复制".to_string();
                 true
             }
+            Msg::CopyDataUri => {
+                if let Some(data_uri) = self.data_uri() {
+                    if let Some(window) = web_sys::window() {
+                        let navigator = window.navigator();
+                        let size = format_size(data_uri.len() as u64);
+                        let clipboard = navigator.clipboard();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let promise = clipboard.write_text(&data_uri);
+                            let _ = JsFuture::from(promise).await;
+                        });
+                        self.copy_uri_button_text = format!("已复制 {}", size);
+                        let link = ctx.link().clone();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            gloo_timers::future::sleep(std::time::Duration::from_millis(2000)).await;
+                            link.send_message(Msg::ResetCopyUriButton);
+                        });
+                    }
+                }
+                true
+            }
+            Msg::ResetCopyUriButton => {
+                self.copy_uri_button_text = "复制Data URI".to_string();
+                true
+            }
+            Msg::CopyCompressedBase64 => {
+                if let Some(compressed) = &self.compressed_base64 {
+                    if let Some(window) = web_sys::window() {
+                        let compressed = compressed.clone();
+                        let size = format_size(compressed.len() as u64);
+                        let clipboard = window.navigator().clipboard();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let promise = clipboard.write_text(&compressed);
+                            let _ = JsFuture::from(promise).await;
+                        });
+                        self.compressed_copy_button_text = format!("已复制 {}", size);
+                        let link = ctx.link().clone();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            gloo_timers::future::sleep(std::time::Duration::from_millis(2000)).await;
+                            link.send_message(Msg::ResetCompressedCopyButton);
+                        });
+                    }
+                }
+                true
+            }
+            Msg::ResetCompressedCopyButton => {
+                self.compressed_copy_button_text = "复制压缩后Base64".to_string();
+                true
+            }
+            Msg::CopySha256 => {
+                if let Some(info) = &self.image_info {
+                    if let Some(window) = web_sys::window() {
+                        let sha256 = info.sha256.clone();
+                        let clipboard = window.navigator().clipboard();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let promise = clipboard.write_text(&sha256);
+                            let _ = JsFuture::from(promise).await;
+                        });
+                        self.sha256_copy_text = "已复制".to_string();
+                        let link = ctx.link().clone();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            gloo_timers::future::sleep(std::time::Duration::from_millis(2000)).await;
+                            link.send_message(Msg::ResetSha256Copy);
+                        });
+                    }
+                }
+                true
+            }
+            Msg::ResetSha256Copy => {
+                self.sha256_copy_text = "复制".to_string();
+                true
+            }
+            Msg::CopyMd5 => {
+                if let Some(info) = &self.image_info {
+                    if let Some(window) = web_sys::window() {
+                        let md5 = info.md5.clone();
+                        let clipboard = window.navigator().clipboard();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let promise = clipboard.write_text(&md5);
+                            let _ = JsFuture::from(promise).await;
+                        });
+                        self.md5_copy_text = "已复制".to_string();
+                        let link = ctx.link().clone();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            gloo_timers::future::sleep(std::time::Duration::from_millis(2000)).await;
+                            link.send_message(Msg::ResetMd5Copy);
+                        });
+                    }
+                }
+                true
+            }
+            Msg::ResetMd5Copy => {
+                self.md5_copy_text = "复制".to_string();
+                true
+            }
             Msg::UpdateImageInfo(dimensions, aspect_ratio) => {
                 if let Some(info) = &mut self.image_info {
                     info.dimensions = dimensions;
@@ -147,6 +410,43 @@ impl Component for Model {
                 }
                 true
             }
+            Msg::ToggleMode => {
+                self.mode = match self.mode {
+                    Mode::Encode => Mode::Decode,
+                    Mode::Decode => Mode::Encode,
+                };
+                self.reset_result();
+                true
+            }
+            Msg::DecodeInput(text) => {
+                self.decode_text = text;
+                true
+            }
+            Msg::DecodeSubmit => {
+                match decode_base64_input(&self.decode_text) {
+                    Ok((mime, bytes)) => {
+                        self.decode_error = None;
+                        self.mime_type = mime;
+                        self.strip_exif = false;
+                        self.image_info = Some(ImageInfo {
+                            format: get_file_format_from_mime(&self.mime_type),
+                            size: format_size(bytes.len() as u64),
+                            dimensions: String::from("加载中..."),
+                            mime_type: self.mime_type.clone(),
+                            aspect_ratio: String::from("加载中..."),
+                            ..ImageInfo::default()
+                        });
+                        self.set_download_url(&bytes);
+                        let base64 = STANDARD.encode(&bytes);
+                        ctx.link().send_message(Msg::Loaded(base64));
+                        ctx.link().send_message(Msg::RawLoaded(bytes));
+                    }
+                    Err(err) => {
+                        self.decode_error = Some(err);
+                    }
+                }
+                true
+            }
         }
     }
 
@@ -166,6 +466,13 @@ impl Component for Model {
             }
         });
 
+        let toggle_mode = ctx.link().callback(|_| Msg::ToggleMode);
+        let on_decode_input = ctx.link().callback(|e: InputEvent| {
+            let textarea: HtmlTextAreaElement = e.target_unchecked_into();
+            Msg::DecodeInput(textarea.value())
+        });
+        let on_decode_submit = ctx.link().callback(|_| Msg::DecodeSubmit);
+
         let copy_base64 = ctx.link().callback(|_| Msg::CopyBase64);
 
         let copy_button_class = if self.copy_button_text.starts_with("已复制") {
@@ -176,25 +483,96 @@ impl Component for Model {
 
         let toggle_modal = ctx.link().callback(|_| Msg::ToggleModal);
 
+        let on_drag_over = ctx.link().callback(|e: DragEvent| {
+            e.prevent_default();
+            Msg::DragOver
+        });
+        let on_drag_leave = ctx.link().callback(|e: DragEvent| {
+            e.prevent_default();
+            Msg::DragLeave
+        });
+        let on_drop = ctx.link().callback(Msg::Drop);
+
+        // `onchange` (fires on release/blur) rather than `oninput`, since each
+        // recompression runs a full decode+resize+encode on the main thread.
+        let on_quality_change = ctx.link().callback(|e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            Msg::UpdateQuality(input.value().parse().unwrap_or(DEFAULT_QUALITY))
+        });
+        let on_max_dimension_change = ctx.link().callback(|e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            Msg::UpdateMaxDimension(input.value().parse().unwrap_or(DEFAULT_MAX_DIMENSION))
+        });
+        let on_toggle_strip_exif = ctx.link().callback(|_: Event| Msg::ToggleStripExif);
+        let copy_data_uri = ctx.link().callback(|_| Msg::CopyDataUri);
+        let copy_sha256 = ctx.link().callback(|_| Msg::CopySha256);
+        let copy_md5 = ctx.link().callback(|_| Msg::CopyMd5);
+        let copy_compressed_base64 = ctx.link().callback(|_| Msg::CopyCompressedBase64);
+        let mime_type = self.mime_type_or_default().to_string();
+
+        let copy_uri_button_class = if self.copy_uri_button_text.starts_with("已复制") {
+            "copy-button copied"
+        } else {
+            "copy-button"
+        };
+
+        let copy_compressed_button_class = if self.compressed_copy_button_text.starts_with("已复制") {
+            "copy-button copied"
+        } else {
+            "copy-button"
+        };
+
         html! {
             <div class="container">
                 <h1>{ "图片上传生成 Base64" }</h1>
+                <button class="mode-toggle" onclick={toggle_mode}>
+                    {
+                        if self.mode == Mode::Encode {
+                            "切换到解码模式"
+                        } else {
+                            "切换到编码模式"
+                        }
+                    }
+                </button>
+
+                if self.mode == Mode::Decode {
+                    <div class="decode-container">
+                        <textarea
+                            class="decode-input"
+                            placeholder="粘贴Base64或Data URI..."
+                            value={self.decode_text.clone()}
+                            oninput={on_decode_input}
+                        />
+                        <button class="decode-button" onclick={on_decode_submit}>
+                            { "解码" }
+                        </button>
+                        if let Some(error) = &self.decode_error {
+                            <p class="decode-error">{ error }</p>
+                        }
+                    </div>
+                }
+
                 <div class="image-container">
+                if self.mode == Mode::Encode {
                     <input type="file" id="file-input" accept="image/*" onchange={on_change} class="file-input" />
-                    <label 
-                        for="file-input" 
+                    <label
+                        for="file-input"
                         class={classes!(
                             "upload-area",
-                            self.base64_data.is_some().then_some("has-image")
+                            self.base64_data.is_some().then_some("has-image"),
+                            self.drag_active.then_some("drag-active")
                         )}
+                        ondragover={on_drag_over}
+                        ondragleave={on_drag_leave}
+                        ondrop={on_drop}
                     >
                         {
                             if let Some(base64) = &self.base64_data {
                                 html! {
                                     <>
-                                        <img 
-                                            src={format!("data:image/png;base64,{}", base64)} 
-                                            class="visible" 
+                                        <img
+                                            src={format!("data:{};base64,{}", mime_type, base64)}
+                                            class="visible"
                                             alt="Image Preview"
                                         />
                                         <button 
@@ -210,6 +588,7 @@ impl Component for Model {
                             }
                         }
                     </label>
+                }
 
                     if let Some(info) = &self.image_info {
                         <div class="image-info">
@@ -233,18 +612,109 @@ impl Component for Model {
                                 <span class="info-label">{ "纵横比" }</span>
                                 <span class="info-value">{ &info.aspect_ratio }</span>
                             </div>
+                            <div class="info-item">
+                                <span class="info-label">{ "压缩后大小" }</span>
+                                <span class="info-value">{ &info.compressed_size }</span>
+                            </div>
+                            <div class="info-item">
+                                <span class="info-label">{ "相机型号" }</span>
+                                <span class="info-value">{ &info.exif_camera }</span>
+                            </div>
+                            <div class="info-item">
+                                <span class="info-label">{ "拍摄方向" }</span>
+                                <span class="info-value">{ &info.exif_orientation }</span>
+                            </div>
+                            <div class="info-item">
+                                <span class="info-label">{ "拍摄时间" }</span>
+                                <span class="info-value">{ &info.exif_timestamp }</span>
+                            </div>
+                            <div class="info-item">
+                                <span class="info-label">{ "GPS坐标" }</span>
+                                <span class="info-value">{ &info.exif_gps }</span>
+                            </div>
+                            <div class="info-item">
+                                <span class="info-label">{ "SHA-256" }</span>
+                                <span class="info-value hash-value">{ &info.sha256 }</span>
+                                <button class="hash-copy-button" onclick={copy_sha256}>
+                                    { &self.sha256_copy_text }
+                                </button>
+                            </div>
+                            <div class="info-item">
+                                <span class="info-label">{ "MD5" }</span>
+                                <span class="info-value hash-value">{ &info.md5 }</span>
+                                <button class="hash-copy-button" onclick={copy_md5}>
+                                    { &self.md5_copy_text }
+                                </button>
+                            </div>
+                        </div>
+                    }
+
+                    if matches!(&self.image_info, Some(info) if info.exif_has_gps) {
+                        <p class="privacy-note">
+                            { "⚠ 该图片包含GPS位置信息，建议开启\"移除EXIF\"后再分享" }
+                        </p>
+                    }
+
+                    if self.mode == Mode::Encode && self.image_info.is_some() {
+                        <label class="exif-toggle">
+                            <input
+                                type="checkbox"
+                                checked={self.strip_exif}
+                                onchange={on_toggle_strip_exif}
+                            />
+                            { "移除EXIF" }
+                        </label>
+                    }
+
+                    if self.mode == Mode::Encode && self.image_info.is_some() {
+                        <div class="compression-settings">
+                            <label class="compression-field">
+                                { format!("压缩质量：{}", self.quality) }
+                                <input
+                                    type="range"
+                                    min="1"
+                                    max="100"
+                                    value={self.quality.to_string()}
+                                    onchange={on_quality_change}
+                                />
+                            </label>
+                            <label class="compression-field">
+                                { "最大边长（px）" }
+                                <input
+                                    type="number"
+                                    min="1"
+                                    value={self.max_dimension.to_string()}
+                                    onchange={on_max_dimension_change}
+                                />
+                            </label>
                         </div>
                     }
                 </div>
 
+                if self.mode == Mode::Decode {
+                    if let Some(base64) = &self.base64_data {
+                        <div class="decode-preview">
+                            <img
+                                src={format!("data:{};base64,{}", mime_type, base64)}
+                                alt="Decoded preview"
+                            />
+                            if let Some(url) = &self.decoded_object_url {
+                                <a class="download-button" href={url.clone()} download="image">
+                                    { "下载图片" }
+                                </a>
+                            }
+                        </div>
+                    }
+                }
+
                 if self.modal_open {
                     if let Some(base64) = &self.base64_data {
                         <div class="modal-overlay active" onclick={toggle_modal.clone()}>
                             <div class="modal-content" onclick={|e: MouseEvent| e.stop_propagation()}>
                                 <button class="modal-close" onclick={toggle_modal}></button>
-                                <img 
-                                    src={format!("data:image/png;base64,{}", base64)} 
-                                    alt="Full size preview" 
+                                <img
+                                    src={format!("data:{};base64,{}", mime_type, base64)}
+                                    alt="Full size preview"
                                 />
                             </div>
                         </div>
@@ -274,15 +744,312 @@ impl Component for Model {
                                 }
                             }
                         </button>
+                        <button
+                            class={copy_uri_button_class}
+                            onclick={copy_data_uri}
+                        >
+                            {
+                                if self.copy_uri_button_text.starts_with("已复制") {
+                                    let parts: Vec<&str> = self.copy_uri_button_text.splitn(2, ' ').collect();
+                                    html! {
+                                        <>
+                                            <span class="copy-text">{ parts[0] }</span>
+                                            <span class="copy-size">{ parts[1] }</span>
+                                        </>
+                                    }
+                                } else {
+                                    html! { &self.copy_uri_button_text }
+                                }
+                            }
+                        </button>
                     </div>
                 } else {
                     <p class="base64-output">{ "未选择文件" }</p>
                 }
+
+                if self.mode == Mode::Encode {
+                    if let Some(compressed) = &self.compressed_base64 {
+                        <div class="base64-output-container">
+                            <div class="base64-output">
+                                { compressed }
+                            </div>
+                            <button
+                                class={copy_compressed_button_class}
+                                onclick={copy_compressed_base64}
+                            >
+                                {
+                                    if self.compressed_copy_button_text.starts_with("已复制") {
+                                        let parts: Vec<&str> = self.compressed_copy_button_text.splitn(2, ' ').collect();
+                                        html! {
+                                            <>
+                                                <span class="copy-text">{ parts[0] }</span>
+                                                <span class="copy-size">{ parts[1] }</span>
+                                            </>
+                                        }
+                                    } else {
+                                        html! { &self.compressed_copy_button_text }
+                                    }
+                                }
+                            </button>
+                        </div>
+                    }
+                }
             </div>
         }
     }
 }
 
+impl Model {
+    /// The detected MIME type, falling back to `image/png` until one is known.
+    fn mime_type_or_default(&self) -> &str {
+        if self.mime_type.is_empty() {
+            "image/png"
+        } else {
+            &self.mime_type
+        }
+    }
+
+    /// Builds the full `data:<mime>;base64,...` URI for the current image.
+    fn data_uri(&self) -> Option<String> {
+        self.base64_data
+            .as_ref()
+            .map(|base64| format!("data:{};base64,{}", self.mime_type_or_default(), base64))
+    }
+
+    /// Clears the current result (encoded or decoded) so switching modes
+    /// doesn't leave a stale preview or info panel on screen.
+    fn reset_result(&mut self) {
+        self.base64_data = None;
+        self.image_info = None;
+        self.original_bytes = None;
+        self.compressed_base64 = None;
+        self.mime_type = String::new();
+        self.strip_exif = false;
+        self.decode_error = None;
+        self.revoke_download_url();
+    }
+
+    /// Creates (and remembers) an object URL for downloading the decoded
+    /// image, revoking whichever URL backed the previous decode.
+    fn set_download_url(&mut self, bytes: &[u8]) {
+        self.revoke_download_url();
+        self.decoded_object_url = make_object_url(bytes, self.mime_type_or_default());
+    }
+
+    fn revoke_download_url(&mut self) {
+        if let Some(url) = self.decoded_object_url.take() {
+            let _ = Url::revoke_object_url(&url);
+        }
+    }
+
+    /// Re-encodes `original_bytes` without metadata and swaps it into
+    /// `base64_data` when EXIF stripping is enabled, restoring the
+    /// untouched original otherwise.
+    fn apply_exif_stripping(&mut self) {
+        let Some(bytes) = &self.original_bytes else {
+            return;
+        };
+        if self.strip_exif {
+            if let Some(clean) = strip_exif_bytes(bytes) {
+                self.base64_data = Some(STANDARD.encode(clean));
+            }
+        } else {
+            self.base64_data = Some(STANDARD.encode(bytes));
+        }
+    }
+
+    /// Re-runs compression against `original_bytes` using the current quality
+    /// and max-dimension settings, updating `compressed_base64` and the
+    /// `ImageInfo` panel in place.
+    fn recompress(&mut self) {
+        let Some(bytes) = &self.original_bytes else {
+            return;
+        };
+        match compress_image(bytes, self.quality, self.max_dimension) {
+            Some(compressed) => {
+                let compressed_size = format_size(compressed.len() as u64);
+                self.compressed_base64 = Some(STANDARD.encode(&compressed));
+                if let Some(info) = &mut self.image_info {
+                    info.compressed_size = compressed_size;
+                }
+            }
+            None => {
+                self.compressed_base64 = None;
+                if let Some(info) = &mut self.image_info {
+                    info.compressed_size = String::from("压缩失败");
+                }
+            }
+        }
+    }
+}
+
+/// Parses a pasted `data:<mime>;base64,<data>` URI or bare base64 string into
+/// its MIME type (sniffed from the bytes when not given) and decoded bytes.
+fn decode_base64_input(input: &str) -> Result<(String, Vec<u8>), String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(String::from("请输入Base64或Data URI"));
+    }
+
+    let (mime, data) = match input.strip_prefix("data:") {
+        Some(rest) => match rest.split_once(";base64,") {
+            Some((mime, data)) => (mime.to_string(), data),
+            None => return Err(String::from("无法识别的Data URI格式")),
+        },
+        None => (String::new(), input),
+    };
+
+    let bytes = STANDARD
+        .decode(data.trim())
+        .map_err(|_| String::from("无效的Base64数据"))?;
+
+    let mime = if mime.is_empty() {
+        sniff_mime_type(&bytes)
+    } else {
+        mime
+    };
+    Ok((mime, bytes))
+}
+
+/// Derives a display format (e.g. "PNG") from a MIME type like "image/png".
+fn get_file_format_from_mime(mime: &str) -> String {
+    mime.split('/')
+        .last()
+        .map(|s| s.to_uppercase())
+        .unwrap_or_else(|| String::from("未知"))
+}
+
+/// Wraps raw bytes in a `Blob` and returns an object URL suitable for a
+/// `<a download>` link; the caller is responsible for revoking it later.
+fn make_object_url(bytes: &[u8], mime: &str) -> Option<String> {
+    let array = Uint8Array::from(bytes);
+    let parts = Array::new();
+    parts.push(&array);
+
+    let mut options = BlobPropertyBag::new();
+    options.type_(mime);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options).ok()?;
+    Url::create_object_url_with_blob(&blob).ok()
+}
+
+/// Hex-encoded SHA-256 of the raw image bytes, for integrity checks and dedup.
+fn compute_sha256(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Hex-encoded MD5 of the raw image bytes, kept alongside SHA-256 for tools
+/// that still key on the shorter legacy hash.
+fn compute_md5(bytes: &[u8]) -> String {
+    format!("{:x}", md5::compute(bytes))
+}
+
+/// Sniffs the common image magic bytes when the browser didn't report a MIME
+/// type (e.g. some drag-and-drop sources leave `file.type` empty).
+fn sniff_mime_type(bytes: &[u8]) -> String {
+    let mime = if bytes.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
+        "image/png"
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        "image/gif"
+    } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        "image/webp"
+    } else if bytes.starts_with(b"BM") {
+        "image/bmp"
+    } else if bytes.starts_with(b"<?xml") || bytes.starts_with(b"<svg") {
+        "image/svg+xml"
+    } else {
+        "image/png"
+    };
+    mime.to_string()
+}
+
+struct ExifSummary {
+    camera: String,
+    orientation: String,
+    timestamp: String,
+    gps: String,
+    has_gps: bool,
+}
+
+/// Reads the EXIF tags commonly embedded by cameras and phones out of the raw
+/// image bytes. Missing tags fall back to "无" rather than leaving blanks.
+fn parse_exif(bytes: &[u8]) -> ExifSummary {
+    let none = || String::from("无");
+    let Ok(exif) = exif::Reader::new().read_from_container(&mut Cursor::new(bytes)) else {
+        return ExifSummary {
+            camera: none(),
+            orientation: none(),
+            timestamp: none(),
+            gps: none(),
+            has_gps: false,
+        };
+    };
+
+    let field = |tag: exif::Tag| {
+        exif.get_field(tag, exif::In::PRIMARY)
+            .map(|f| f.display_value().with_unit(&exif).to_string())
+    };
+
+    let latitude = field(exif::Tag::GPSLatitude);
+    let longitude = field(exif::Tag::GPSLongitude);
+    let gps = match (&latitude, &longitude) {
+        (Some(lat), Some(lon)) => format!("{}, {}", lat, lon),
+        _ => none(),
+    };
+
+    ExifSummary {
+        camera: field(exif::Tag::Model).unwrap_or_else(none),
+        orientation: field(exif::Tag::Orientation).unwrap_or_else(none),
+        timestamp: field(exif::Tag::DateTimeOriginal).unwrap_or_else(none),
+        gps,
+        has_gps: latitude.is_some() && longitude.is_some(),
+    }
+}
+
+/// Decodes and re-encodes the image in its original format so no EXIF (or
+/// other metadata) survives into the output bytes.
+///
+/// Limitation: animated GIFs are flattened to their first frame, since the
+/// `image` crate's GIF encoder here only writes a single frame.
+fn strip_exif_bytes(bytes: &[u8]) -> Option<Vec<u8>> {
+    let format = image::guess_format(bytes).ok()?;
+    let img = image::load_from_memory_with_format(bytes, format).ok()?;
+    let output_format = match format {
+        // The crate's `From<ImageFormat>` impl defaults JPEG to quality 75,
+        // which would visibly recompress the image just to drop metadata.
+        image::ImageFormat::Jpeg => ImageOutputFormat::Jpeg(EXIF_STRIP_JPEG_QUALITY),
+        other => ImageOutputFormat::from(other),
+    };
+    let mut buf = Vec::new();
+    img.write_to(&mut Cursor::new(&mut buf), output_format).ok()?;
+    Some(buf)
+}
+
+/// Downscales to `max_dimension` (if larger) and re-encodes as JPEG at `quality`.
+fn compress_image(bytes: &[u8], quality: u8, max_dimension: u32) -> Option<Vec<u8>> {
+    let img = image::load_from_memory(bytes).ok()?;
+    let img = if img.width() > max_dimension || img.height() > max_dimension {
+        img.resize(max_dimension, max_dimension, FilterType::Lanczos3)
+    } else {
+        img
+    };
+
+    // JPEG has no alpha channel, so flatten onto an opaque buffer first —
+    // otherwise `write_to` rejects RGBA sources (e.g. PNG screenshots) with
+    // `Unsupported`.
+    let rgb = image::DynamicImage::ImageRgb8(img.to_rgb8());
+
+    let mut buf = Vec::new();
+    rgb.write_to(&mut Cursor::new(&mut buf), ImageOutputFormat::Jpeg(quality))
+        .ok()?;
+    Some(buf)
+}
+
 fn format_size(size: u64) -> String {
     const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
     let mut size = size as f64;
@@ -325,3 +1092,77 @@ fn gcd(mut a: u32, mut b: u32) -> u32 {
 fn main() {
     yew::Renderer::<Model>::new().render();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_base64_input_parses_data_uri() {
+        let uri = format!("data:image/png;base64,{}", STANDARD.encode(b"hello"));
+        let (mime, bytes) = decode_base64_input(&uri).unwrap();
+        assert_eq!(mime, "image/png");
+        assert_eq!(bytes, b"hello");
+    }
+
+    #[test]
+    fn decode_base64_input_sniffs_bare_base64() {
+        let png_bytes = [0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A];
+        let (mime, bytes) = decode_base64_input(&STANDARD.encode(png_bytes)).unwrap();
+        assert_eq!(mime, "image/png");
+        assert_eq!(bytes, png_bytes);
+    }
+
+    #[test]
+    fn decode_base64_input_rejects_invalid_base64() {
+        assert!(decode_base64_input("not-valid-base64!!!").is_err());
+    }
+
+    #[test]
+    fn decode_base64_input_rejects_empty_input() {
+        assert!(decode_base64_input("   ").is_err());
+    }
+
+    #[test]
+    fn decode_base64_input_rejects_malformed_data_uri() {
+        assert!(decode_base64_input("data:image/png,not-base64-marker").is_err());
+    }
+
+    #[test]
+    fn sniff_mime_type_detects_known_formats() {
+        assert_eq!(
+            sniff_mime_type(&[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A]),
+            "image/png"
+        );
+        assert_eq!(sniff_mime_type(&[0xFF, 0xD8, 0xFF, 0xE0]), "image/jpeg");
+        assert_eq!(sniff_mime_type(b"GIF89a"), "image/gif");
+        assert_eq!(
+            sniff_mime_type(b"RIFF\x00\x00\x00\x00WEBPVP8 "),
+            "image/webp"
+        );
+    }
+
+    #[test]
+    fn sniff_mime_type_falls_back_to_png_for_unknown_bytes() {
+        assert_eq!(sniff_mime_type(b"not an image"), "image/png");
+    }
+
+    #[test]
+    fn get_file_format_from_mime_uppercases_subtype() {
+        assert_eq!(get_file_format_from_mime("image/png"), "PNG");
+        assert_eq!(get_file_format_from_mime("image/jpeg"), "JPEG");
+    }
+
+    #[test]
+    fn compute_sha256_matches_known_vector() {
+        assert_eq!(
+            compute_sha256(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn compute_md5_matches_known_vector() {
+        assert_eq!(compute_md5(b""), "d41d8cd98f00b204e9800998ecf8427e");
+    }
+}